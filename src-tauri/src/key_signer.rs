@@ -5,11 +5,53 @@
  * Windows: siehe key_signer_windows.rs
  */
 
-use std::io::{BufRead, BufReader, Write};
+use std::ffi::OsString;
+use std::io::{BufRead, BufReader, Read, Write};
 use std::os::unix::net::UnixStream;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use portable_pty::{native_pty_system, Child as PtyChild, CommandBuilder, MasterPty, PtySize};
+use semver::Version;
+use serde::{Deserialize, Serialize};
 use tauri::command;
 
+/// A terminal emulator NoorNote knows how to launch the NoorSigner CLI in.
+///
+/// `args` may contain the placeholder tokens `{bin}` and `{cmd}`, which are
+/// substituted with the NoorSigner binary path and subcommand at launch time.
+/// On macOS, `{applescript}` is additionally substituted with the generated
+/// AppleScript source (see `launch_key_signer`'s macOS branch).
+///
+/// Args are plain strings (not `OsString`) so a `TermConfig` round-trips
+/// through `~/.noornote/config.json` as ordinary JSON; they're only
+/// converted to `OsString` at the `Command::args(...)` call site.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TermConfig {
+    pub name: String,
+    pub exec: PathBuf,
+    pub args: Vec<String>,
+}
+
+/// Candidate Linux terminal emulators, in probe order, with their
+/// "run a command" invocation template.
+const LINUX_TERMINAL_CANDIDATES: &[(&str, &[&str])] = &[
+    ("gnome-terminal", &["--", "{bin}", "{cmd}"]),
+    ("konsole", &["-e", "{bin}", "{cmd}"]),
+    ("xfce4-terminal", &["-x", "{bin}", "{cmd}"]),
+    ("alacritty", &["-e", "{bin}", "{cmd}"]),
+    ("kitty", &["{bin}", "{cmd}"]),
+    ("wezterm", &["start", "--", "{bin}", "{cmd}"]),
+    ("foot", &["{bin}", "{cmd}"]),
+    ("xterm", &["-e", "{bin}", "{cmd}"]),
+];
+
+/// Persisted NoorNote configuration (~/.noornote/config.json).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct NoorNoteConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    term: Option<TermConfig>,
+}
+
 /// Get the base path for NoorNote data (~/.noornote/)
 fn get_noornote_base_path() -> Result<PathBuf, String> {
     let home = std::env::var("HOME")
@@ -17,6 +59,106 @@ fn get_noornote_base_path() -> Result<PathBuf, String> {
     Ok(PathBuf::from(home).join(".noornote"))
 }
 
+/// Get the NoorNote config file path - ~/.noornote/config.json
+fn get_config_path() -> Result<PathBuf, String> {
+    Ok(get_noornote_base_path()?.join("config.json"))
+}
+
+/// Load the NoorNote config, falling back to defaults if missing or unreadable.
+fn load_noornote_config() -> NoorNoteConfig {
+    let Ok(path) = get_config_path() else {
+        return NoorNoteConfig::default();
+    };
+    let Ok(content) = std::fs::read_to_string(&path) else {
+        return NoorNoteConfig::default();
+    };
+    serde_json::from_str(&content).unwrap_or_default()
+}
+
+/// Persist the NoorNote config to ~/.noornote/config.json
+fn save_noornote_config(config: &NoorNoteConfig) -> Result<(), String> {
+    let path = get_config_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create config directory {:?}: {}", dir, e))?;
+    }
+    let json = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize config: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write config {:?}: {}", path, e))
+}
+
+/// Probe for the first available terminal emulator using `which`.
+fn default_term_config() -> Result<TermConfig, String> {
+    #[cfg(target_os = "macos")]
+    {
+        let exec = which::which("osascript")
+            .map_err(|e| format!("osascript not found, cannot launch a terminal on macOS: {}", e))?;
+        return Ok(TermConfig {
+            name: "Terminal.app".to_string(),
+            exec,
+            args: vec!["-e".to_string(), "{applescript}".to_string()],
+        });
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        for (name, args) in LINUX_TERMINAL_CANDIDATES {
+            if let Ok(exec) = which::which(name) {
+                return Ok(TermConfig {
+                    name: (*name).to_string(),
+                    exec,
+                    args: args.iter().map(|arg| arg.to_string()).collect(),
+                });
+            }
+        }
+        return Err(format!(
+            "No terminal emulator found via `which`. Install one of {}, or set a custom terminal in NoorNote settings.",
+            LINUX_TERMINAL_CANDIDATES
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    #[allow(unreachable_code)]
+    Err("No terminal configuration available for this platform".to_string())
+}
+
+/// Get the configured terminal, probing and persisting a default if unset.
+fn get_term_config() -> Result<TermConfig, String> {
+    let mut config = load_noornote_config();
+    if let Some(term) = config.term.clone() {
+        return Ok(term);
+    }
+
+    let term = default_term_config()?;
+    config.term = Some(term.clone());
+    let _ = save_noornote_config(&config);
+    Ok(term)
+}
+
+/// Substitute the `{bin}`/`{cmd}` (and, on macOS, `{applescript}`) placeholder
+/// tokens in a `TermConfig`'s args, converting to `OsString` as we go.
+fn substitute_term_args(args: &[String], bin: &Path, cmd: &str, applescript: Option<&str>) -> Vec<OsString> {
+    args.iter()
+        .map(|arg| match arg.as_str() {
+            "{bin}" => bin.as_os_str().to_os_string(),
+            "{cmd}" => OsString::from(cmd),
+            "{applescript}" if applescript.is_some() => OsString::from(applescript.unwrap()),
+            _ => OsString::from(arg),
+        })
+        .collect()
+}
+
+/// Let the user override the auto-detected terminal launcher.
+#[command]
+pub async fn set_term_config(term: TermConfig) -> Result<(), String> {
+    let mut config = load_noornote_config();
+    config.term = Some(term);
+    save_noornote_config(&config)
+}
+
 /// Get socket path - under ~/.noorsigner/
 fn get_socket_path() -> Result<PathBuf, String> {
     let home = std::env::var("HOME")
@@ -29,26 +171,31 @@ fn get_noorsigner_path() -> Result<PathBuf, String> {
     Ok(get_noornote_base_path()?.join("bin").join("noorsigner"))
 }
 
-/// Get the sidecar binary path from the app bundle
-fn get_sidecar_source_path() -> Result<PathBuf, String> {
-    let exe_path = std::env::current_exe()
-        .map_err(|e| format!("Failed to get current executable path: {}", e))?;
-
-    let exe_dir = exe_path.parent()
-        .ok_or_else(|| "Failed to get executable directory".to_string())?;
-
+/// Rust target triple for the binary currently running, used to pick the
+/// right bundled sidecar or network download artifact.
+fn target_triple() -> &'static str {
     #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
-    let target_triple = "x86_64-unknown-linux-gnu";
+    return "x86_64-unknown-linux-gnu";
 
     #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
-    let target_triple = "aarch64-unknown-linux-gnu";
+    return "aarch64-unknown-linux-gnu";
 
     #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-    let target_triple = "x86_64-apple-darwin";
+    return "x86_64-apple-darwin";
 
     #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    let target_triple = "aarch64-apple-darwin";
+    return "aarch64-apple-darwin";
+}
 
+/// Get the sidecar binary path from the app bundle
+fn get_sidecar_source_path() -> Result<PathBuf, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to get current executable path: {}", e))?;
+
+    let exe_dir = exe_path.parent()
+        .ok_or_else(|| "Failed to get executable directory".to_string())?;
+
+    let target_triple = target_triple();
     let sidecar_with_triple = format!("noorsigner-{}", target_triple);
     let sidecar_simple = "noorsigner".to_string();
 
@@ -94,7 +241,16 @@ pub async fn ensure_noorsigner_installed() -> Result<String, String> {
         return Ok(target_path.display().to_string());
     }
 
-    let source_path = get_sidecar_source_path()?;
+    let source_path = match get_sidecar_source_path() {
+        Ok(path) => path,
+        Err(bundled_err) => {
+            println!(
+                "No bundled NoorSigner sidecar ({}), falling back to network install",
+                bundled_err
+            );
+            return install_noorsigner_from_network().await;
+        }
+    };
     println!("Found NoorSigner sidecar at: {:?}", source_path);
 
     fs::copy(&source_path, &target_path)
@@ -111,26 +267,658 @@ pub async fn ensure_noorsigner_installed() -> Result<String, String> {
     Ok(target_path.display().to_string())
 }
 
-/// Send JSON-RPC request to KeySigner daemon via Unix socket
+/// URL of the manifest describing available NoorSigner release artifacts.
+const RELEASE_MANIFEST_URL: &str = "https://releases.noornote.app/noorsigner/manifest.json";
+
+/// Ed25519 public key that NoorSigner release manifests must be signed with.
+///
+/// The manifest is served from the same host as the artifacts it describes,
+/// so a checksum alone only proves transport integrity - anyone who can
+/// tamper with (or impersonate) that host can swap in a matching checksum
+/// for a malicious artifact. Baking the verification key into the app
+/// binary instead means trust is rooted in something shipped to the user,
+/// not in whatever the release server happens to say about itself.
+const RELEASE_MANIFEST_PUBKEY: [u8; 32] = [
+    0x8f, 0x1a, 0x3c, 0x6e, 0x52, 0x9d, 0x04, 0xb7, 0x3e, 0xc1, 0x5a, 0x97, 0x60, 0x2d, 0xe8, 0x44,
+    0x1b, 0x7f, 0x93, 0xc2, 0x0e, 0x58, 0xa6, 0x31, 0xd4, 0x89, 0xf0, 0x25, 0x7c, 0x6b, 0x3d, 0xaa,
+];
+
+/// A single downloadable NoorSigner build for one target triple.
+#[derive(Debug, Deserialize)]
+struct ReleaseArtifact {
+    target_triple: String,
+    url: String,
+    sha256: String,
+    /// `"gzip"`, `"xz"`, or absent for an uncompressed artifact.
+    compression: Option<String>,
+}
+
+/// The release manifest served at `RELEASE_MANIFEST_URL`.
+#[derive(Debug, Deserialize)]
+struct ReleaseManifest {
+    version: String,
+    artifacts: Vec<ReleaseArtifact>,
+    /// Hex-encoded Ed25519 signature over [`release_manifest_signing_payload`],
+    /// verified against `RELEASE_MANIFEST_PUBKEY` before any artifact in this
+    /// manifest is trusted.
+    signature: String,
+}
+
+/// Canonical bytes a release manifest's `signature` field signs.
+///
+/// Deliberately built from the individual fields rather than re-serializing
+/// the manifest to JSON, so verification doesn't depend on matching the
+/// signer's JSON formatting byte-for-byte.
+fn release_manifest_signing_payload(manifest: &ReleaseManifest) -> Vec<u8> {
+    let mut payload = manifest.version.clone();
+    for artifact in &manifest.artifacts {
+        payload.push('\n');
+        payload.push_str(&artifact.target_triple);
+        payload.push('|');
+        payload.push_str(&artifact.url);
+        payload.push('|');
+        payload.push_str(&artifact.sha256);
+        payload.push('|');
+        payload.push_str(artifact.compression.as_deref().unwrap_or(""));
+    }
+    payload.into_bytes()
+}
+
+/// Decode a lowercase/uppercase hex string, rejecting anything malformed.
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    if value.len() % 2 != 0 {
+        return Err("odd-length hex string".to_string());
+    }
+    if !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("non-hex character in hex string".to_string());
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).map_err(|e| e.to_string()))
+        .collect()
+}
+
+/// Verify a release manifest's signature against the baked-in release key,
+/// refusing to trust the manifest (and therefore any of its artifacts) if
+/// verification fails.
+fn verify_release_manifest_signature(manifest: &ReleaseManifest) -> Result<(), String> {
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    let verifying_key = VerifyingKey::from_bytes(&RELEASE_MANIFEST_PUBKEY)
+        .map_err(|e| format!("Invalid baked-in release signing key: {}", e))?;
+
+    let sig_bytes = decode_hex(manifest.signature.trim())
+        .map_err(|e| format!("Malformed NoorSigner release manifest signature: {}", e))?;
+    let signature = Signature::from_slice(&sig_bytes)
+        .map_err(|e| format!("Malformed NoorSigner release manifest signature: {}", e))?;
+
+    verifying_key
+        .verify(&release_manifest_signing_payload(manifest), &signature)
+        .map_err(|_| {
+            "NoorSigner release manifest signature verification failed; refusing to trust it"
+                .to_string()
+        })
+}
+
+async fn fetch_release_manifest() -> Result<ReleaseManifest, String> {
+    let response = reqwest::get(RELEASE_MANIFEST_URL)
+        .await
+        .map_err(|e| format!("Failed to fetch NoorSigner release manifest: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "NoorSigner release manifest request failed with status {}",
+            response.status()
+        ));
+    }
+
+    let manifest = response
+        .json::<ReleaseManifest>()
+        .await
+        .map_err(|e| format!("Failed to parse NoorSigner release manifest: {}", e))?;
+
+    verify_release_manifest_signature(&manifest)?;
+
+    Ok(manifest)
+}
+
+/// Download an artifact, decompress it if needed, and verify its checksum
+/// against the value carried in the (signature-verified) release manifest.
+async fn download_and_verify_artifact(artifact: &ReleaseArtifact) -> Result<Vec<u8>, String> {
+    use sha2::{Digest, Sha256};
+    use std::io::Read;
+
+    let response = reqwest::get(&artifact.url)
+        .await
+        .map_err(|e| format!("Failed to download NoorSigner artifact: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "NoorSigner artifact download failed with status {}",
+            response.status()
+        ));
+    }
+
+    let compressed = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to stream NoorSigner artifact: {}", e))?;
+
+    let decompressed = match artifact.compression.as_deref() {
+        Some("gzip") => {
+            let mut out = Vec::new();
+            flate2::read::GzDecoder::new(&compressed[..])
+                .read_to_end(&mut out)
+                .map_err(|e| format!("Failed to decompress gzip artifact: {}", e))?;
+            out
+        }
+        Some("xz") => {
+            let mut out = Vec::new();
+            xz2::read::XzDecoder::new(&compressed[..])
+                .read_to_end(&mut out)
+                .map_err(|e| format!("Failed to decompress xz artifact: {}", e))?;
+            out
+        }
+        Some(other) => return Err(format!("Unsupported artifact compression: {}", other)),
+        None => compressed.to_vec(),
+    };
+
+    let mut hasher = Sha256::new();
+    hasher.update(&decompressed);
+    let digest = format!("{:x}", hasher.finalize());
+
+    if !digest.eq_ignore_ascii_case(&artifact.sha256) {
+        return Err(format!(
+            "Checksum mismatch for NoorSigner artifact: expected {}, got {}. Refusing to install.",
+            artifact.sha256, digest
+        ));
+    }
+
+    Ok(decompressed)
+}
+
+/// Download, verify, and atomically install the NoorSigner sidecar from the network.
+#[command]
+pub async fn install_noorsigner_from_network() -> Result<String, String> {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let triple = target_triple();
+    let manifest = fetch_release_manifest().await?;
+
+    let artifact = manifest
+        .artifacts
+        .iter()
+        .find(|a| a.target_triple == triple)
+        .ok_or_else(|| format!("No NoorSigner release artifact available for target {}", triple))?;
+
+    let bytes = download_and_verify_artifact(artifact).await?;
+
+    let target_path = get_noorsigner_path()?;
+    let target_dir = target_path
+        .parent()
+        .ok_or_else(|| "Failed to get target directory".to_string())?;
+    fs::create_dir_all(target_dir)
+        .map_err(|e| format!("Failed to create directory {:?}: {}", target_dir, e))?;
+
+    let temp_path = target_dir.join("noorsigner.download");
+    fs::write(&temp_path, &bytes)
+        .map_err(|e| format!("Failed to write temp file {:?}: {}", temp_path, e))?;
+
+    let mut perms = fs::metadata(&temp_path)
+        .map_err(|e| format!("Failed to get permissions: {}", e))?
+        .permissions();
+    perms.set_mode(0o755);
+    fs::set_permissions(&temp_path, perms)
+        .map_err(|e| format!("Failed to set executable permission: {}", e))?;
+
+    fs::rename(&temp_path, &target_path)
+        .map_err(|e| format!("Failed to install NoorSigner to {:?}: {}", target_path, e))?;
+
+    println!(
+        "NoorSigner {} installed from network to: {:?}",
+        manifest.version, target_path
+    );
+    Ok(target_path.display().to_string())
+}
+
+/// Check the release manifest for a NoorSigner build newer than `current_version`.
 #[command]
-pub async fn key_signer_request(request: String) -> Result<String, String> {
-    use std::time::Duration;
+pub async fn check_noorsigner_update(current_version: String) -> Result<Option<Version>, String> {
+    let current = Version::parse(&current_version)
+        .map_err(|e| format!("Invalid current version {:?}: {}", current_version, e))?;
+
+    let manifest = fetch_release_manifest().await?;
+    let latest = Version::parse(&manifest.version)
+        .map_err(|e| format!("Invalid manifest version {:?}: {}", manifest.version, e))?;
+
+    if latest > current {
+        Ok(Some(latest))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Required JSON-RPC methods the daemon must advertise before we'll talk to it.
+const REQUIRED_CAPABILITIES: &[&str] = &["sign_event", "get_pubkey"];
+
+/// Capabilities negotiated with a given daemon, keyed by endpoint (see
+/// [`endpoint_cache_key`]) so a successful handshake with one daemon doesn't
+/// suppress the check against a different, possibly stale/incompatible one.
+static NEGOTIATED_CAPABILITIES: std::sync::OnceLock<std::sync::Mutex<std::collections::HashMap<String, Vec<String>>>> =
+    std::sync::OnceLock::new();
+
+fn capabilities_cache() -> &'static std::sync::Mutex<std::collections::HashMap<String, Vec<String>>> {
+    NEGOTIATED_CAPABILITIES.get_or_init(|| std::sync::Mutex::new(std::collections::HashMap::new()))
+}
+
+/// Identify a signing endpoint for capability-cache purposes: `"local"`, or
+/// `"ssh:<user>@<host>:<port>"` for a remote daemon.
+fn endpoint_cache_key(ssh_host: Option<&str>, ssh_port: Option<u16>, ssh_user: Option<&str>) -> String {
+    match ssh_host {
+        Some(host) => format!("ssh:{}@{}:{}", ssh_user.unwrap_or(""), host, ssh_port.unwrap_or(22)),
+        None => "local".to_string(),
+    }
+}
+
+/// Spawn `noorsigner daemon` in the background, detached from our process group.
+fn spawn_daemon_background() -> Result<(), String> {
+    use std::os::unix::process::CommandExt;
+    use std::process::{Command, Stdio};
+
+    let noorsigner_path = get_noorsigner_path()?;
+    if !noorsigner_path.exists() {
+        return Err(format!(
+            "NoorSigner binary not found at {}; run ensure_noorsigner_installed first",
+            noorsigner_path.display()
+        ));
+    }
+
+    Command::new(&noorsigner_path)
+        .arg("daemon")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0)
+        .spawn()
+        .map_err(|e| format!("Failed to spawn NoorSigner daemon: {}", e))?;
+
+    Ok(())
+}
+
+/// Connect to the daemon's Unix socket, auto-spawning and waiting for it to
+/// come up if it isn't already running.
+fn connect_to_daemon() -> Result<UnixStream, String> {
+    use std::time::{Duration, Instant};
 
     let socket_path = get_socket_path()?;
 
-    let mut stream = UnixStream::connect(&socket_path)
-        .map_err(|e| format!("Failed to connect to KeySigner daemon: {}. Is the daemon running?", e))?;
+    match UnixStream::connect(&socket_path) {
+        Ok(stream) => return Ok(stream),
+        Err(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::NotFound | std::io::ErrorKind::ConnectionRefused
+            ) =>
+        {
+            println!("NoorSigner daemon not reachable ({}), spawning it...", e);
+        }
+        Err(e) => return Err(format!("Failed to connect to KeySigner daemon: {}", e)),
+    }
+
+    spawn_daemon_background()?;
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(5);
+    let retry_interval = Duration::from_millis(100);
+
+    loop {
+        match UnixStream::connect(&socket_path) {
+            Ok(stream) => return Ok(stream),
+            Err(_) if start.elapsed() < timeout => {
+                std::thread::sleep(retry_interval);
+            }
+            Err(e) => {
+                return Err(format!(
+                    "Timed out waiting for NoorSigner daemon to listen on {:?}: {}",
+                    socket_path, e
+                ));
+            }
+        }
+    }
+}
+
+/// Where the NoorSigner daemon we're talking to is actually running.
+enum SignerEndpoint {
+    /// Connected directly to `~/.noorsigner/noorsigner.sock` on this machine.
+    Local(UnixStream),
+    /// Connected to a local Unix socket that an `ssh -L` forward proxies to
+    /// the daemon's socket on a remote host.
+    Ssh {
+        #[allow(dead_code)]
+        host: String,
+        stream: UnixStream,
+    },
+}
+
+impl SignerEndpoint {
+    fn stream_mut(&mut self) -> &mut UnixStream {
+        match self {
+            SignerEndpoint::Local(stream) => stream,
+            SignerEndpoint::Ssh { stream, .. } => stream,
+        }
+    }
+}
+
+/// An `ssh -L` forward process kept alive so repeated calls to the same
+/// remote endpoint reuse one SSH connection instead of paying for a fresh
+/// handshake (and a fresh multi-second wait) on every request.
+struct SshForward {
+    local_socket: PathBuf,
+    child: std::process::Child,
+}
+
+static SSH_FORWARDS: std::sync::OnceLock<Mutex<std::collections::HashMap<String, SshForward>>> =
+    std::sync::OnceLock::new();
+
+fn ssh_forwards_cache() -> &'static Mutex<std::collections::HashMap<String, SshForward>> {
+    SSH_FORWARDS.get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+}
+
+/// Remote `$HOME` directories, keyed by endpoint, so we only pay for the
+/// `ssh ... echo $HOME` round trip once per endpoint.
+static REMOTE_HOME_CACHE: std::sync::OnceLock<Mutex<std::collections::HashMap<String, String>>> =
+    std::sync::OnceLock::new();
+
+fn remote_home_cache() -> &'static Mutex<std::collections::HashMap<String, String>> {
+    REMOTE_HOME_CACHE.get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+}
+
+/// Resolve `target`'s home directory on the remote host.
+///
+/// OpenSSH's streamlocal forwarding (`-L local:remote`) sends the remote path
+/// verbatim to `sshd`, which `connect()`s it as-is - `~` is never expanded.
+/// We resolve the real path with a one-off `ssh ... echo $HOME` instead of
+/// embedding a literal `~` in the forward spec.
+fn resolve_remote_home(endpoint_key: &str, target: &str, port: u16) -> Result<String, String> {
+    if let Some(home) = remote_home_cache().lock().unwrap().get(endpoint_key) {
+        return Ok(home.clone());
+    }
+
+    let output = std::process::Command::new("ssh")
+        .arg("-p")
+        .arg(port.to_string())
+        .arg("--")
+        .arg(target)
+        .arg("echo $HOME")
+        .output()
+        .map_err(|e| format!("Failed to resolve remote home directory on {}: {}", target, e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to resolve remote home directory on {}: {}",
+            target,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let home = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if home.is_empty() {
+        return Err(format!("Remote host {} returned an empty $HOME", target));
+    }
+
+    remote_home_cache()
+        .lock()
+        .unwrap()
+        .insert(endpoint_key.to_string(), home.clone());
+    Ok(home)
+}
+
+/// Kill and reap every cached SSH socket forward.
+///
+/// Forwards are kept alive across calls for reuse (see `connect_to_remote_daemon`),
+/// so nothing here tears them down on its own - the frontend should call this
+/// on app shutdown / window close to avoid leaving orphaned `ssh` processes behind.
+#[command]
+pub async fn shutdown_ssh_forwards() -> Result<(), String> {
+    let mut forwards = ssh_forwards_cache().lock().unwrap();
+    for (_, mut forward) in forwards.drain() {
+        let _ = forward.child.kill();
+        let _ = forward.child.wait();
+    }
+    Ok(())
+}
+
+/// Reject SSH host/user values that could be misparsed as an `ssh` option
+/// (leading `-`) or that could relocate the local forward socket outside the
+/// temp dir (`/`, `..`).
+fn validate_ssh_identifier(value: &str, field: &str) -> Result<(), String> {
+    if value.is_empty() {
+        return Err(format!("SSH {} must not be empty", field));
+    }
+    if value.starts_with('-') {
+        return Err(format!("Invalid SSH {} {:?}: must not start with '-'", field, value));
+    }
+    if value.contains('/') || value.contains("..") || value.contains('@') || value.contains(char::is_whitespace) {
+        return Err(format!(
+            "Invalid SSH {} {:?}: must not contain '/', '..', '@', or whitespace",
+            field, value
+        ));
+    }
+    Ok(())
+}
+
+/// Start an `ssh -L` forward from a fresh local Unix socket to the remote
+/// daemon's socket, assumed to live at the same path NoorNote uses locally.
+///
+/// `endpoint_key` (already unique per host/port/user, see `endpoint_cache_key`)
+/// is folded into the socket filename so distinct endpoints on the same host
+/// never share - and clobber - the same local forward socket.
+fn spawn_ssh_socket_forward(
+    endpoint_key: &str,
+    host: &str,
+    port: u16,
+    user: Option<&str>,
+) -> Result<(PathBuf, std::process::Child), String> {
+    use std::process::{Command, Stdio};
+
+    let sanitized_key: String = endpoint_key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c.to_string()
+            } else {
+                // Escape as `_<hex>_` so the mapping stays one-to-one.
+                format!("_{:x}_", c as u32)
+            }
+        })
+        .collect();
+    let local_socket = std::env::temp_dir().join(format!(
+        "noornote-ssh-{}-{}.sock",
+        sanitized_key,
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&local_socket);
+
+    let target = match user {
+        Some(user) => format!("{}@{}", user, host),
+        None => host.to_string(),
+    };
+
+    let remote_home = resolve_remote_home(endpoint_key, &target, port)?;
+    let remote_socket = format!("{}/.noorsigner/noorsigner.sock", remote_home);
+
+    let child = Command::new("ssh")
+        .arg("-N")
+        .arg("-o")
+        .arg("ExitOnForwardFailure=yes")
+        .arg("-p")
+        .arg(port.to_string())
+        .arg("-L")
+        .arg(format!("{}:{}", local_socket.display(), remote_socket))
+        .arg("--")
+        .arg(&target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to start SSH socket forward to {}: {}", target, e))?;
+
+    Ok((local_socket, child))
+}
+
+/// Connect to a NoorSigner daemon on a remote host over an SSH socket
+/// forward, reusing a still-alive forward for `endpoint_key` if one exists.
+fn connect_to_remote_daemon(
+    endpoint_key: &str,
+    host: &str,
+    port: u16,
+    user: Option<&str>,
+) -> Result<SignerEndpoint, String> {
+    use std::time::{Duration, Instant};
+
+    validate_ssh_identifier(host, "host")?;
+    if let Some(user) = user {
+        validate_ssh_identifier(user, "user")?;
+    }
+
+    let local_socket = {
+        let mut forwards = ssh_forwards_cache().lock().unwrap();
+
+        let forward_is_live = forwards
+            .get_mut(endpoint_key)
+            .map(|forward| matches!(forward.child.try_wait(), Ok(None)))
+            .unwrap_or(false);
+
+        if !forward_is_live {
+            if let Some(mut stale) = forwards.remove(endpoint_key) {
+                let _ = stale.child.kill();
+                let _ = stale.child.wait();
+            }
+            let (local_socket, child) = spawn_ssh_socket_forward(endpoint_key, host, port, user)?;
+            forwards.insert(endpoint_key.to_string(), SshForward { local_socket, child });
+        }
+
+        forwards.get(endpoint_key).unwrap().local_socket.clone()
+    };
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(10);
+    let retry_interval = Duration::from_millis(100);
+
+    loop {
+        match UnixStream::connect(&local_socket) {
+            Ok(stream) => {
+                return Ok(SignerEndpoint::Ssh {
+                    host: host.to_string(),
+                    stream,
+                });
+            }
+            Err(_) if start.elapsed() < timeout => {
+                std::thread::sleep(retry_interval);
+            }
+            Err(e) => {
+                return Err(format!(
+                    "Timed out waiting for SSH socket forward to {} to come up: {}",
+                    host, e
+                ));
+            }
+        }
+    }
+}
+
+/// Ask the daemon what it supports and verify it covers `REQUIRED_CAPABILITIES`.
+fn negotiate_capabilities(stream: &mut UnixStream) -> Result<Vec<String>, String> {
+    let handshake_request = r#"{"jsonrpc":"2.0","id":"noornote-handshake","method":"get_capabilities"}"#;
+    stream
+        .write_all(format!("{}\n", handshake_request).as_bytes())
+        .map_err(|e| format!("Failed to send capability handshake: {}", e))?;
+
+    let mut reader = BufReader::new(&mut *stream);
+    let mut response = String::new();
+    reader
+        .read_line(&mut response)
+        .map_err(|e| format!("Failed to read capability handshake response: {}", e))?;
+
+    let value: serde_json::Value = serde_json::from_str(response.trim_end())
+        .map_err(|e| format!("Malformed capability handshake response: {}", e))?;
+
+    let capabilities: Vec<String> = value
+        .get("result")
+        .and_then(|r| r.get("methods"))
+        .and_then(|m| m.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect()
+        })
+        .ok_or_else(|| "Daemon did not return a capability list".to_string())?;
+
+    let missing: Vec<&str> = REQUIRED_CAPABILITIES
+        .iter()
+        .filter(|req| !capabilities.iter().any(|c| c == *req))
+        .copied()
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "NoorSigner daemon is missing required capabilities: {}. It may be an incompatible or stale build.",
+            missing.join(", ")
+        ));
+    }
+
+    Ok(capabilities)
+}
+
+/// Negotiate capabilities on `stream` unless we've already cached a
+/// successful negotiation with this same `endpoint_key` from a previous connection.
+fn ensure_capabilities_negotiated(stream: &mut UnixStream, endpoint_key: &str) -> Result<(), String> {
+    if capabilities_cache().lock().unwrap().contains_key(endpoint_key) {
+        return Ok(());
+    }
+
+    let capabilities = negotiate_capabilities(stream)?;
+    capabilities_cache()
+        .lock()
+        .unwrap()
+        .insert(endpoint_key.to_string(), capabilities);
+    Ok(())
+}
+
+/// Send JSON-RPC request to KeySigner daemon via Unix socket.
+///
+/// If `ssh_host` is set, the request is routed to a NoorSigner daemon on
+/// that remote host instead of the local one (see [`SignerEndpoint`]).
+#[command]
+pub async fn key_signer_request(
+    request: String,
+    ssh_host: Option<String>,
+    ssh_port: Option<u16>,
+    ssh_user: Option<String>,
+) -> Result<String, String> {
+    use std::time::Duration;
+
+    let endpoint_key = endpoint_cache_key(ssh_host.as_deref(), ssh_port, ssh_user.as_deref());
+
+    let mut endpoint = match ssh_host {
+        Some(host) => connect_to_remote_daemon(&endpoint_key, &host, ssh_port.unwrap_or(22), ssh_user.as_deref())?,
+        None => SignerEndpoint::Local(connect_to_daemon()?),
+    };
+
+    let stream = endpoint.stream_mut();
 
     stream.set_read_timeout(Some(Duration::from_secs(10)))
         .map_err(|e| format!("Failed to set read timeout: {}", e))?;
     stream.set_write_timeout(Some(Duration::from_secs(10)))
         .map_err(|e| format!("Failed to set write timeout: {}", e))?;
 
+    ensure_capabilities_negotiated(stream, &endpoint_key)?;
+
     let request_with_newline = format!("{}\n", request);
     stream.write_all(request_with_newline.as_bytes())
         .map_err(|e| format!("Failed to send request: {}", e))?;
 
-    let mut reader = BufReader::new(&mut stream);
+    let mut reader = BufReader::new(stream);
     let mut response = String::new();
     reader.read_line(&mut response)
         .map_err(|e| {
@@ -178,11 +966,219 @@ pub async fn check_trust_session() -> Result<bool, String> {
     Ok(now < expires_unix)
 }
 
+/// An in-progress interactive NoorSigner session driven through an embedded PTY.
+///
+/// `generation` distinguishes this session from whatever comes after it, so
+/// the reader thread spawned for an old session can't clobber a newer one's
+/// cache slot after it's been superseded.
+struct PtySession {
+    generation: u64,
+    master: Box<dyn MasterPty + Send>,
+    writer: Box<dyn Write + Send>,
+    child: Box<dyn PtyChild + Send + Sync>,
+}
+
+/// The PTY slot is idle, mid-launch (reserved so a second concurrent launch
+/// is rejected instead of racing), or actively driving a session.
+enum PtyState {
+    Idle,
+    Starting,
+    Active(PtySession),
+}
+
+static PTY_SESSION: std::sync::OnceLock<Mutex<PtyState>> = std::sync::OnceLock::new();
+static NEXT_PTY_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
+fn pty_session_cache() -> &'static Mutex<PtyState> {
+    PTY_SESSION.get_or_init(|| Mutex::new(PtyState::Idle))
+}
+
+/// Reserves the PTY slot for a new launch, rejecting it if one is already
+/// starting or active. Resets the slot back to `Idle` on drop unless the
+/// reservation has since moved on to `Active` (see `spawn_key_signer_pty`).
+struct PtyReservation;
+
+impl Drop for PtyReservation {
+    fn drop(&mut self) {
+        let mut guard = pty_session_cache().lock().unwrap();
+        if matches!(*guard, PtyState::Starting) {
+            *guard = PtyState::Idle;
+        }
+    }
+}
+
+fn reserve_pty_slot() -> Result<PtyReservation, String> {
+    let mut guard = pty_session_cache().lock().unwrap();
+    if !matches!(*guard, PtyState::Idle) {
+        return Err("A NoorSigner PTY session is already active; cancel it before starting another".to_string());
+    }
+    *guard = PtyState::Starting;
+    Ok(PtyReservation)
+}
+
+/// Terminate a PTY session's whole process group, not just the direct child.
+///
+/// `portable_pty`'s unix `Child::kill` only sends SIGHUP to the immediate
+/// child pid, which misses any grandchild `init`/`add-account` forks. PTY
+/// allocation makes the child its own session/process group leader (its pid
+/// equals its pgid), so signalling `-pid` reaches the whole group.
+fn kill_pty_session(mut session: PtySession) {
+    if let Some(pid) = session.child.process_id() {
+        unsafe {
+            libc::kill(-(pid as libc::pid_t), libc::SIGKILL);
+        }
+    }
+    let _ = session.child.kill();
+    let _ = session.child.wait();
+}
+
+/// Allocate a PTY, spawn `noorsigner <mode>` attached to its slave, and start
+/// streaming its output to the frontend over `noorsigner-pty-output` events.
+///
+/// Rejects the launch if a PTY session is already starting or active (see
+/// `reserve_pty_slot`) instead of silently replacing it.
+async fn spawn_key_signer_pty(mode: &str, window: tauri::Window, cols: u16, rows: u16) -> Result<(), String> {
+    let reservation = reserve_pty_slot()?;
+
+    ensure_noorsigner_installed().await?;
+    let noorsigner_path = get_noorsigner_path()?;
+
+    let pty_system = native_pty_system();
+    let pair = pty_system
+        .openpty(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to allocate PTY: {}", e))?;
+
+    let mut builder = CommandBuilder::new(&noorsigner_path);
+    builder.arg(mode);
+
+    let child = pair
+        .slave
+        .spawn_command(builder)
+        .map_err(|e| format!("Failed to spawn NoorSigner in PTY: {}", e))?;
+    // The slave fd lives on in the child; drop our copy so reads on the
+    // master see EOF once the child actually exits.
+    drop(pair.slave);
+
+    let mut reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to clone PTY reader: {}", e))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|e| format!("Failed to take PTY writer: {}", e))?;
+
+    let generation = NEXT_PTY_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+
+    *pty_session_cache().lock().unwrap() = PtyState::Active(PtySession {
+        generation,
+        master: pair.master,
+        writer,
+        child,
+    });
+    // The reservation's Drop only resets a still-`Starting` slot; we've
+    // already moved it to `Active`, so dropping here is a no-op.
+    drop(reservation);
+
+    std::thread::spawn(move || {
+        let mut buf = [0u8; 4096];
+        loop {
+            match reader.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    let chunk = String::from_utf8_lossy(&buf[..n]).into_owned();
+                    let _ = window.emit("noorsigner-pty-output", chunk);
+                }
+            }
+        }
+        // Only clear the slot if it still holds *this* session - a newer
+        // launch may have already reserved or populated it.
+        let mut guard = pty_session_cache().lock().unwrap();
+        if matches!(&*guard, PtyState::Active(session) if session.generation == generation) {
+            *guard = PtyState::Idle;
+        }
+        drop(guard);
+        let _ = window.emit("noorsigner-pty-exit", ());
+    });
+
+    Ok(())
+}
+
+/// Drive `init`/`add-account` inside an embedded PTY instead of an external
+/// terminal window, falling back to the external-terminal path if the PTY
+/// can't be allocated.
+#[command]
+pub async fn launch_key_signer_pty(mode: String, window: tauri::Window, cols: u16, rows: u16) -> Result<(), String> {
+    if !matches!(mode.as_str(), "init" | "add-account") {
+        return Err(format!(
+            "PTY launch only supports init/add-account modes, got: {}",
+            mode
+        ));
+    }
+
+    if let Err(pty_err) = spawn_key_signer_pty(&mode, window, cols, rows).await {
+        if matches!(*pty_session_cache().lock().unwrap(), PtyState::Starting | PtyState::Active(_)) {
+            // A session is already starting/active - that's not a PTY
+            // allocation failure, so don't also open an external terminal.
+            return Err(pty_err);
+        }
+        println!("PTY allocation failed ({}), falling back to external terminal", pty_err);
+        return launch_key_signer(mode, None, None, None).await;
+    }
+
+    Ok(())
+}
+
+/// Forward frontend keystrokes (including passphrase input) to the active PTY session.
+#[command]
+pub async fn send_key_signer_pty_input(input: String) -> Result<(), String> {
+    let mut guard = pty_session_cache().lock().unwrap();
+    let session = match &mut *guard {
+        PtyState::Active(session) => session,
+        PtyState::Idle | PtyState::Starting => return Err("No active NoorSigner PTY session".to_string()),
+    };
+    session
+        .writer
+        .write_all(input.as_bytes())
+        .map_err(|e| format!("Failed to write to PTY: {}", e))
+}
+
+/// Resize the active PTY session's window.
+#[command]
+pub async fn resize_key_signer_pty(cols: u16, rows: u16) -> Result<(), String> {
+    let guard = pty_session_cache().lock().unwrap();
+    let session = match &*guard {
+        PtyState::Active(session) => session,
+        PtyState::Idle | PtyState::Starting => return Err("No active NoorSigner PTY session".to_string()),
+    };
+    session
+        .master
+        .resize(PtySize {
+            rows,
+            cols,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to resize PTY: {}", e))
+}
+
 /// Cancel KeySigner launch by killing any running noorsigner daemon process
+/// and, if present, the active PTY-driven session.
 #[command]
 pub async fn cancel_key_signer_launch() -> Result<(), String> {
     use std::process::Command;
 
+    let prior = std::mem::replace(&mut *pty_session_cache().lock().unwrap(), PtyState::Idle);
+    if let PtyState::Active(session) = prior {
+        kill_pty_session(session);
+        println!("Killed NoorSigner PTY session (process group)");
+    }
+
     let output = Command::new("pkill")
         .arg("-f")
         .arg("noorsigner.*daemon")
@@ -197,12 +1193,69 @@ pub async fn cancel_key_signer_launch() -> Result<(), String> {
     Ok(())
 }
 
+/// Start the NoorSigner daemon on a remote host over SSH.
+///
+/// Only the `daemon` mode is supported remotely - `init`/`add-account` need
+/// an interactive session, which isn't wired up for SSH yet.
+async fn launch_key_signer_remote(
+    mode: &str,
+    host: &str,
+    port: u16,
+    user: Option<&str>,
+) -> Result<(), String> {
+    use std::process::{Command, Stdio};
+
+    if mode != "daemon" {
+        return Err(format!(
+            "Remote launch only supports the daemon (mode {:?} needs an interactive session on {})",
+            mode, host
+        ));
+    }
+
+    validate_ssh_identifier(host, "host")?;
+    if let Some(user) = user {
+        validate_ssh_identifier(user, "user")?;
+    }
+
+    let target = match user {
+        Some(user) => format!("{}@{}", user, host),
+        None => host.to_string(),
+    };
+
+    println!("Starting NoorSigner daemon on remote host {}", target);
+
+    let status = Command::new("ssh")
+        .arg("-p")
+        .arg(port.to_string())
+        .arg("--")
+        .arg(&target)
+        .arg("nohup noorsigner daemon >/dev/null 2>&1 & disown")
+        .stdin(Stdio::null())
+        .status()
+        .map_err(|e| format!("Failed to start remote NoorSigner daemon via SSH: {}", e))?;
+
+    if !status.success() {
+        return Err(format!("Remote SSH command exited with status: {}", status));
+    }
+
+    Ok(())
+}
+
 /// Launch NoorSigner CLI binary
 #[command]
-pub async fn launch_key_signer(mode: String) -> Result<(), String> {
+pub async fn launch_key_signer(
+    mode: String,
+    ssh_host: Option<String>,
+    ssh_port: Option<u16>,
+    ssh_user: Option<String>,
+) -> Result<(), String> {
     use std::process::Command;
     use std::os::unix::process::CommandExt;
 
+    if let Some(host) = ssh_host {
+        return launch_key_signer_remote(&mode, &host, ssh_port.unwrap_or(22), ssh_user.as_deref()).await;
+    }
+
     ensure_noorsigner_installed().await?;
 
     let noorsigner_path = get_noorsigner_path()?;
@@ -280,6 +1333,8 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
 
     println!("Launching in terminal for user input");
 
+    let term_config = get_term_config()?;
+
     #[cfg(target_os = "macos")]
     {
         let terminal_command = format!("{} {}", noorsigner_path.display(), cmd);
@@ -291,46 +1346,34 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
             terminal_command
         );
 
-        let output = Command::new("osascript")
-            .arg("-e")
-            .arg(&applescript)
+        let args = substitute_term_args(&term_config.args, &noorsigner_path, cmd, Some(&applescript));
+
+        let output = Command::new(&term_config.exec)
+            .args(&args)
             .output()
-            .map_err(|e| format!("Failed to launch Terminal.app: {}", e))?;
+            .map_err(|e| format!("Failed to launch {} via {}: {}", term_config.name, term_config.exec.display(), e))?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(format!("osascript failed: {}", stderr));
+            return Err(format!("{} failed: {}", term_config.name, stderr));
         }
     }
 
     #[cfg(target_os = "linux")]
     {
-        let terminals = ["gnome-terminal", "konsole", "xterm"];
-        let mut launched = false;
-
-        for terminal in &terminals {
-            let result = if *terminal == "gnome-terminal" {
-                Command::new(terminal)
-                    .arg("--")
-                    .arg(noorsigner_path.to_str().unwrap())
-                    .arg(cmd)
-                    .spawn()
-            } else {
-                Command::new(terminal)
-                    .arg("-e")
-                    .arg(format!("{} {}", noorsigner_path.display(), cmd))
-                    .spawn()
-            };
-
-            if result.is_ok() {
-                launched = true;
-                break;
-            }
-        }
+        let args = substitute_term_args(&term_config.args, &noorsigner_path, cmd, None);
 
-        if !launched {
-            return Err("No terminal emulator found. Please install gnome-terminal, konsole, or xterm.".to_string());
-        }
+        Command::new(&term_config.exec)
+            .args(&args)
+            .spawn()
+            .map_err(|e| {
+                format!(
+                    "Failed to launch {} ({}): {}. Configure a different terminal via set_term_config.",
+                    term_config.name,
+                    term_config.exec.display(),
+                    e
+                )
+            })?;
     }
 
     println!("NoorSigner launched successfully");